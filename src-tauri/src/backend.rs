@@ -0,0 +1,465 @@
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::api::process::{Command, CommandChild, CommandEvent};
+use tauri::async_runtime::Receiver;
+use tauri::{AppHandle, Manager, State};
+
+use crate::logging::{emit_console_event, ConsoleEvent, LogHistory};
+
+const READINESS_POLL_INTERVAL_MS: u64 = 250;
+const READINESS_TIMEOUT_MS: u64 = 30_000;
+
+/// Restart at most this many times in a row; the next consecutive crash gives up.
+const MAX_RESTARTS: u32 = 5;
+const BASE_BACKOFF_MS: u64 = 500;
+const MAX_BACKOFF_MS: u64 = 30_000;
+
+/// How long to wait for the backend to exit on its own before SIGKILLing it.
+const DEFAULT_GRACE_PERIOD_MS: u64 = 5_000;
+const STOP_POLL_INTERVAL_MS: u64 = 100;
+
+/// Lifecycle state of the backend process, broadcast to the frontend as `backend-status`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "state")]
+pub enum BackendStatus {
+    Starting,
+    Ready,
+    Failed { reason: String },
+}
+
+/// `backend-status` payload: the lifecycle state plus the base URL the backend is (or will
+/// be) reachable at, once a port has been chosen.
+#[derive(Debug, Clone, Serialize)]
+struct BackendStatusEvent {
+    #[serde(flatten)]
+    status: BackendStatus,
+    url: Option<String>,
+}
+
+/// Broadcast on every supervised restart attempt so the UI can surface "backend restarting
+/// (attempt N, retrying in Xms)" rather than just going blank.
+#[derive(Debug, Clone, Serialize)]
+struct RestartEvent {
+    attempt: u32,
+    delay_ms: u64,
+}
+
+/// Result of `stop_backend`: whether the backend exited on its own within the grace period
+/// or had to be force-killed.
+#[derive(Debug, Clone, Serialize)]
+pub struct StopResult {
+    pub graceful: bool,
+    pub message: String,
+}
+
+pub struct BackendState {
+    child: Option<CommandChild>,
+    pub(crate) logs: LogHistory,
+    status: BackendStatus,
+    port: Option<u16>,
+}
+
+impl Default for BackendState {
+    fn default() -> Self {
+        Self {
+            child: None,
+            logs: LogHistory::default(),
+            status: BackendStatus::Starting,
+            port: None,
+        }
+    }
+}
+
+/// `should_run` and `restart_attempts` live outside the state mutex so `stop_backend` can flip
+/// them without contending with the supervisor loop's log/event handling.
+pub struct BackendProcess {
+    pub(crate) state: Mutex<BackendState>,
+    should_run: AtomicBool,
+    restart_attempts: AtomicU32,
+    /// Bumped every time `supervise_backend` spawns a new readiness probe. A probe captures
+    /// the generation at spawn time and checks it again before reporting its result, so a
+    /// stale probe from an already-superseded restart cycle can't overwrite the status a
+    /// newer cycle has since set.
+    probe_generation: AtomicU32,
+}
+
+impl Default for BackendProcess {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(BackendState::default()),
+            should_run: AtomicBool::new(false),
+            restart_attempts: AtomicU32::new(0),
+            probe_generation: AtomicU32::new(0),
+        }
+    }
+}
+
+fn set_status(app_handle: &AppHandle, state: &mut BackendState, status: BackendStatus) {
+    state.status = status.clone();
+    let url = state.port.map(|port| format!("http://127.0.0.1:{}", port));
+    let _ = app_handle.emit_all("backend-status", BackendStatusEvent { status, url });
+}
+
+/// Ask the OS for a free port by binding `:0`, then release it immediately so uvicorn (or the
+/// sidecar) can bind it right after. Narrow but acceptable race: nothing else on the machine
+/// should grab it in between.
+fn pick_free_port() -> Result<u16, String> {
+    let listener =
+        TcpListener::bind(("127.0.0.1", 0)).map_err(|e| format!("Failed to reserve a port: {}", e))?;
+    listener
+        .local_addr()
+        .map(|addr| addr.port())
+        .map_err(|e| format!("Failed to read reserved port: {}", e))
+}
+
+fn spawn_child(port: u16) -> Result<(Receiver<CommandEvent>, CommandChild), String> {
+    // Debug builds run the backend with Python directly; release builds use the bundled sidecar
+    if cfg!(debug_assertions) {
+        // Development mode: use Python directly
+        Command::new("python3")
+            .args([
+                "-m",
+                "uvicorn",
+                "src.api.main:app",
+                "--host",
+                "127.0.0.1",
+                "--port",
+                &port.to_string(),
+            ])
+            .spawn()
+            .map_err(|e| format!("Failed to start backend (dev mode): {}", e))
+    } else {
+        // Production mode: use sidecar binary
+        Command::new_sidecar("docuextract-backend")
+            .map_err(|e| format!("Failed to create sidecar command: {}", e))?
+            .env("BACKEND_PORT", port.to_string())
+            .spawn()
+            .map_err(|e| format!("Failed to start backend sidecar: {}", e))
+    }
+}
+
+#[tauri::command]
+pub fn start_backend(state: State<BackendProcess>, app_handle: AppHandle) -> Result<String, String> {
+    // Use `should_run` itself as the atomic reservation: only the caller that flips it from
+    // false to true gets to spawn a supervisor. This closes the window between checking
+    // `child.is_some()` and committing to a spawn, during which two concurrent calls (e.g. the
+    // auto-start thread racing a frontend retry) could both see "not running" and each spawn
+    // their own supervisor loop, leaking one of the resulting processes.
+    if state
+        .should_run
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return Ok("Backend already running".to_string());
+    }
+
+    state.restart_attempts.store(0, Ordering::SeqCst);
+
+    tauri::async_runtime::spawn(supervise_backend(app_handle));
+
+    Ok("Backend started".to_string())
+}
+
+/// Keep the backend alive: spawn it, stream its output, and if it dies unexpectedly while
+/// `should_run` is still set, re-spawn it after an exponentially growing backoff.
+async fn supervise_backend(app_handle: AppHandle) {
+    loop {
+        let port = match pick_free_port() {
+            Ok(port) => port,
+            Err(e) => {
+                log::error!("backend: {}", e);
+                if let Ok(mut state) = app_handle.state::<BackendProcess>().state.lock() {
+                    set_status(&app_handle, &mut state, BackendStatus::Failed { reason: e });
+                }
+                app_handle.state::<BackendProcess>().should_run.store(false, Ordering::SeqCst);
+                return;
+            }
+        };
+
+        let spawned = spawn_child(port);
+        let (mut rx, child) = match spawned {
+            Ok(pair) => pair,
+            Err(e) => {
+                log::error!("backend: {}", e);
+                if let Ok(mut state) = app_handle.state::<BackendProcess>().state.lock() {
+                    set_status(&app_handle, &mut state, BackendStatus::Failed { reason: e });
+                }
+                app_handle.state::<BackendProcess>().should_run.store(false, Ordering::SeqCst);
+                return;
+            }
+        };
+
+        {
+            let backend = app_handle.state::<BackendProcess>();
+            let mut state = match backend.state.lock() {
+                Ok(guard) => guard,
+                Err(_) => return,
+            };
+            state.child = Some(child);
+            state.port = Some(port);
+            set_status(&app_handle, &mut state, BackendStatus::Starting);
+        }
+
+        let generation = app_handle
+            .state::<BackendProcess>()
+            .probe_generation
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+        spawn_readiness_probe(app_handle.clone(), port, generation);
+
+        let mut exit_code: Option<i32> = None;
+        while let Some(event) = rx.recv().await {
+            let console_event = match event {
+                CommandEvent::Stdout(line) => Some(ConsoleEvent::new("info", "backend", line)),
+                CommandEvent::Stderr(line) => Some(ConsoleEvent::new("error", "backend", line)),
+                CommandEvent::Error(error) => {
+                    Some(ConsoleEvent::new("error", "backend", format!("fatal: {}", error)))
+                }
+                CommandEvent::Terminated(payload) => {
+                    exit_code = payload.code;
+                    Some(ConsoleEvent::new(
+                        "info",
+                        "backend",
+                        format!("Process terminated with code: {:?}", payload.code),
+                    ))
+                }
+                _ => None,
+            };
+
+            if let Some(console_event) = console_event {
+                if let Ok(mut state) = app_handle.state::<BackendProcess>().state.lock() {
+                    emit_console_event(&app_handle, &mut state.logs, console_event);
+                }
+            }
+
+            if exit_code.is_some() {
+                break;
+            }
+        }
+
+        let backend = app_handle.state::<BackendProcess>();
+        if let Ok(mut state) = backend.state.lock() {
+            state.child = None;
+        }
+
+        if !backend.should_run.load(Ordering::SeqCst) {
+            // `stop_backend` requested this shutdown; don't treat it as a crash.
+            return;
+        }
+
+        match exit_code {
+            Some(code) if code != 0 => {
+                let attempt = backend.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                if attempt > MAX_RESTARTS {
+                    let reason = format!("backend crashed {} times; giving up", attempt);
+                    log::error!("backend: {}", reason);
+                    if let Ok(mut state) = backend.state.lock() {
+                        set_status(&app_handle, &mut state, BackendStatus::Failed { reason });
+                    }
+                    backend.should_run.store(false, Ordering::SeqCst);
+                    return;
+                }
+
+                let delay_ms = BASE_BACKOFF_MS
+                    .saturating_mul(1 << (attempt - 1).min(16))
+                    .min(MAX_BACKOFF_MS);
+                log::warn!(
+                    "backend: restarting (attempt {}, retrying in {}ms)",
+                    attempt,
+                    delay_ms
+                );
+                let _ = app_handle.emit_all("backend-restart", RestartEvent { attempt, delay_ms });
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+
+                if !backend.should_run.load(Ordering::SeqCst) {
+                    // `stop_backend` ran while this backoff was sleeping; honor it instead of
+                    // respawning a process nothing will be tracking or able to stop afterwards.
+                    return;
+                }
+                // loop again and re-spawn
+            }
+            _ => {
+                // clean exit (or unknown code) - nothing to supervise anymore
+                backend.should_run.store(false, Ordering::SeqCst);
+                return;
+            }
+        }
+    }
+}
+
+/// Poll the backend's port until it accepts connections (or we give up), then flip
+/// `BackendState::status` to `Ready`/`Failed` so the UI can stop showing its spinner.
+///
+/// `generation` is the value of `BackendProcess::probe_generation` at the time this probe was
+/// spawned. Restarts can happen well inside `READINESS_TIMEOUT_MS`, so a probe checks its
+/// generation is still current before reporting — otherwise a stale probe for an
+/// already-dead port could clobber the status a later restart cycle has since set.
+fn spawn_readiness_probe(app_handle: AppHandle, port: u16, generation: u32) {
+    tauri::async_runtime::spawn(async move {
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(READINESS_TIMEOUT_MS);
+
+        let status = loop {
+            if tokio::net::TcpStream::connect(("127.0.0.1", port))
+                .await
+                .is_ok()
+            {
+                break BackendStatus::Ready;
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                break BackendStatus::Failed {
+                    reason: "timed out waiting for backend to bind its port".to_string(),
+                };
+            }
+
+            tokio::time::sleep(Duration::from_millis(READINESS_POLL_INTERVAL_MS)).await;
+        };
+
+        let backend = app_handle.state::<BackendProcess>();
+        if backend.probe_generation.load(Ordering::SeqCst) != generation {
+            // A newer restart cycle has already spawned its own probe; this one's result is
+            // for a port nothing is listening on anymore, so don't touch the shared status.
+            return;
+        }
+
+        match &status {
+            BackendStatus::Ready => log::info!("backend: ready on port {}", port),
+            BackendStatus::Failed { reason } => log::error!("backend: {}", reason),
+            BackendStatus::Starting => {}
+        }
+
+        if let Ok(mut state) = backend.state.lock() {
+            let became_ready = matches!(status, BackendStatus::Ready);
+            set_status(&app_handle, &mut state, status);
+            if became_ready {
+                // A successful boot clears the crash counter so a later, unrelated crash
+                // gets the full backoff budget rather than inheriting this run's attempts.
+                backend.restart_attempts.store(0, Ordering::SeqCst);
+            }
+        }
+    });
+}
+
+/// Resolve with `true` once the backend has reported `Ready`, fail once it reports `Failed`,
+/// and resolve with `false` if `timeout_ms` passes first, so callers can await actual
+/// readiness instead of sleeping a fixed duration.
+#[tauri::command]
+pub async fn wait_for_backend(
+    state: State<'_, BackendProcess>,
+    timeout_ms: u64,
+) -> Result<bool, String> {
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(timeout_ms);
+
+    loop {
+        {
+            let process_guard = state.state.lock().map_err(|e| e.to_string())?;
+            match &process_guard.status {
+                BackendStatus::Ready => return Ok(true),
+                BackendStatus::Failed { reason } => return Err(reason.clone()),
+                BackendStatus::Starting => {}
+            }
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return Ok(false);
+        }
+
+        tokio::time::sleep(Duration::from_millis(READINESS_POLL_INTERVAL_MS)).await;
+    }
+}
+
+/// Ask the backend to shut down cleanly, give it `grace_period_ms` to exit on its own, and
+/// only SIGKILL it if it's still around afterwards. Used by both the `stop_backend` command
+/// and the `CloseRequested` window handler.
+pub async fn graceful_stop(backend: &BackendProcess, grace_period_ms: Option<u64>) -> Result<StopResult, String> {
+    // Clear `should_run` first so the supervisor loop sees an intentional stop rather than
+    // treating this kill as a crash to restart from.
+    backend.should_run.store(false, Ordering::SeqCst);
+
+    let port = {
+        let process_guard = backend.state.lock().map_err(|e| e.to_string())?;
+        if process_guard.child.is_none() {
+            return Ok(StopResult {
+                graceful: true,
+                message: "Backend not running".to_string(),
+            });
+        }
+        process_guard.port
+    };
+
+    // Prefer a clean shutdown over a bare kill, which can corrupt in-flight extraction
+    // writes or leave temp files behind. The backend may not implement this endpoint, in
+    // which case we just fall through to waiting out the grace period and then killing.
+    if let Some(port) = port {
+        let _ = reqwest::Client::new()
+            .post(format!("http://127.0.0.1:{}/shutdown", port))
+            .send()
+            .await;
+    }
+
+    let deadline = tokio::time::Instant::now()
+        + Duration::from_millis(grace_period_ms.unwrap_or(DEFAULT_GRACE_PERIOD_MS));
+
+    loop {
+        let exited = backend
+            .state
+            .lock()
+            .map_err(|e| e.to_string())?
+            .child
+            .is_none();
+        if exited {
+            return Ok(StopResult {
+                graceful: true,
+                message: "Backend stopped gracefully".to_string(),
+            });
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(STOP_POLL_INTERVAL_MS)).await;
+    }
+
+    let mut process_guard = backend.state.lock().map_err(|e| e.to_string())?;
+    match process_guard.child.take() {
+        Some(child) => {
+            child.kill().map_err(|e| format!("Failed to stop backend: {}", e))?;
+            Ok(StopResult {
+                graceful: false,
+                message: "Backend did not exit within the grace period; force killed".to_string(),
+            })
+        }
+        None => Ok(StopResult {
+            graceful: true,
+            message: "Backend stopped gracefully".to_string(),
+        }),
+    }
+}
+
+#[tauri::command]
+pub async fn stop_backend(
+    state: State<'_, BackendProcess>,
+    grace_period_ms: Option<u64>,
+) -> Result<StopResult, String> {
+    graceful_stop(state.inner(), grace_period_ms).await
+}
+
+#[tauri::command]
+pub fn get_backend_logs(state: State<BackendProcess>) -> Result<Vec<ConsoleEvent>, String> {
+    let process_guard = state.state.lock().map_err(|e| e.to_string())?;
+    Ok(process_guard.logs.snapshot())
+}
+
+/// The backend's actual base URL, since the port is chosen dynamically rather than fixed.
+#[tauri::command]
+pub fn get_backend_url(state: State<BackendProcess>) -> Result<String, String> {
+    let process_guard = state.state.lock().map_err(|e| e.to_string())?;
+    process_guard
+        .port
+        .map(|port| format!("http://127.0.0.1:{}", port))
+        .ok_or_else(|| "Backend has not been started yet".to_string())
+}
@@ -0,0 +1,76 @@
+use std::collections::VecDeque;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+/// Number of console lines retained for replay when the UI console mounts.
+const LOG_HISTORY_CAPACITY: usize = 500;
+
+/// A single line destined for the frontend's live console window.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsoleEvent {
+    pub level: String,
+    pub line: String,
+    pub timestamp: i64,
+    pub source: String,
+}
+
+impl ConsoleEvent {
+    pub fn new(level: &str, source: &str, line: impl Into<String>) -> Self {
+        Self {
+            level: level.to_string(),
+            source: source.to_string(),
+            line: line.into(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+}
+
+/// Bounded ring buffer of recent console events, kept so a freshly-mounted UI can replay
+/// history instead of only seeing lines emitted after it started listening.
+#[derive(Default)]
+pub struct LogHistory(VecDeque<ConsoleEvent>);
+
+impl LogHistory {
+    pub fn push(&mut self, event: ConsoleEvent) {
+        if self.0.len() == LOG_HISTORY_CAPACITY {
+            self.0.pop_front();
+        }
+        self.0.push_back(event);
+    }
+
+    pub fn snapshot(&self) -> Vec<ConsoleEvent> {
+        self.0.iter().cloned().collect()
+    }
+}
+
+/// Record `event` in `history` and forward it to the frontend over the `backend-log` channel.
+pub fn emit_console_event(app_handle: &AppHandle, history: &mut LogHistory, event: ConsoleEvent) {
+    history.push(event.clone());
+    let _ = app_handle.emit_all("backend-log", event);
+}
+
+/// Bridge the app's own `log`/`fern` diagnostics (sidecar spawn failures, restart attempts,
+/// readiness probe results, ...) onto the same `backend-log` channel the Python process
+/// streams into, pushing through the same `LogHistory` ring buffer so `get_backend_logs`
+/// replays one unified timeline instead of missing the Rust-side half of it.
+pub fn init_log_bridge(app_handle: AppHandle) -> Result<(), fern::InitError> {
+    fern::Dispatch::new()
+        .format(move |out, message, record| {
+            let event = ConsoleEvent::new(
+                &record.level().to_string().to_lowercase(),
+                "rust",
+                message.to_string(),
+            );
+            if let Ok(mut state) = app_handle.state::<crate::backend::BackendProcess>().state.lock() {
+                emit_console_event(&app_handle, &mut state.logs, event);
+            } else {
+                let _ = app_handle.emit_all("backend-log", event);
+            }
+            out.finish(format_args!("[{}] {}", record.level(), message))
+        })
+        .level(log::LevelFilter::Info)
+        .chain(std::io::stdout())
+        .apply()?;
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const OLLAMA_BASE_URL: &str = "http://127.0.0.1:11434";
+
+/// One entry from Ollama's `/api/tags` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelInfo {
+    pub name: String,
+    pub size: u64,
+    pub digest: String,
+    pub modified_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<ModelInfo>,
+}
+
+/// Progress update for an in-flight `pull_ollama_model` call, emitted as the NDJSON
+/// lines Ollama streams back from `/api/pull` are parsed.
+#[derive(Debug, Clone, Serialize)]
+struct OllamaPullProgress {
+    model: String,
+    status: String,
+    completed: u64,
+    total: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullProgressLine {
+    status: String,
+    #[serde(default)]
+    completed: u64,
+    #[serde(default)]
+    total: u64,
+}
+
+#[tauri::command]
+pub fn check_ollama() -> Result<bool, String> {
+    let output = std::process::Command::new("ollama")
+        .arg("list")
+        .output()
+        .map_err(|e| format!("Failed to check Ollama: {}", e))?;
+
+    Ok(output.status.success())
+}
+
+#[tauri::command]
+pub async fn list_ollama_models() -> Result<Vec<ModelInfo>, String> {
+    let response = reqwest::get(format!("{}/api/tags", OLLAMA_BASE_URL))
+        .await
+        .map_err(|e| format!("Failed to reach Ollama: {}", e))?;
+
+    let tags: TagsResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Ollama response: {}", e))?;
+
+    Ok(tags.models)
+}
+
+/// Whether `name` (e.g. `"llama3:8b"`) is already pulled locally.
+#[tauri::command]
+pub async fn ensure_ollama_model(name: String) -> Result<bool, String> {
+    let models = list_ollama_models().await?;
+    Ok(models.iter().any(|m| m.name == name))
+}
+
+/// Pull `name`, streaming Ollama's NDJSON progress events to the frontend as
+/// `ollama-pull-progress` events so the UI can render a download bar.
+#[tauri::command]
+pub async fn pull_ollama_model(app_handle: AppHandle, name: String) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let mut response = client
+        .post(format!("{}/api/pull", OLLAMA_BASE_URL))
+        .json(&serde_json::json!({ "name": name, "stream": true }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to start Ollama pull: {}", e))?;
+
+    let mut buffer = String::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed reading Ollama pull stream: {}", e))?
+    {
+        buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+        while let Some(newline_idx) = buffer.find('\n') {
+            let line = buffer[..newline_idx].trim().to_string();
+            buffer.drain(..=newline_idx);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Ok(progress) = serde_json::from_str::<PullProgressLine>(&line) {
+                let _ = app_handle.emit_all(
+                    "ollama-pull-progress",
+                    OllamaPullProgress {
+                        model: name.clone(),
+                        status: progress.status,
+                        completed: progress.completed,
+                        total: progress.total,
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(())
+}